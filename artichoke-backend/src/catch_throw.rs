@@ -0,0 +1,301 @@
+//! Implementation of Ruby's `Kernel#catch`/`Kernel#throw` non-local control
+//! flow.
+//!
+//! `catch` evaluates its block under the shared [`protect::protect`] guard --
+//! the same helper [`eval::Eval::eval`] uses to guard `mrb_load_nstring_cxt`
+//! -- and on unwind inspects the propagating exception: a carrier whose tag matches
+//! the `catch` frame is swallowed and its value returned; anything else
+//! (including a carrier for an outer tag) is re-raised so it can be picked up
+//! by an enclosing `catch` frame or surface as an [`UncaughtThrowError`] at
+//! the top level.
+//!
+//! A genuinely uncaught `throw` (no active `catch` frame has a matching tag)
+//! raises a plain, rescuable [`UncaughtThrowError`], matching MRI. A `throw`
+//! that *does* have a matching `catch` frame instead raises [`Fatal`], which
+//! is not a `StandardError` descendant, so an ordinary `rescue`/`rescue
+//! StandardError` sitting between the `throw` and its matching `catch`
+//! cannot intercept and swallow the carrier before `catch` ever sees it.
+//! This is not complete parity with MRI -- real `throw`/`catch` is a
+//! separate, non-exception jump mechanism that no `rescue` clause (including
+//! `rescue Exception`) can observe at all, and reaching that would mean
+//! changing how exceptions are dispatched to `rescue` clauses in the VM,
+//! which is out of scope here -- but it closes the common case of a bare or
+//! `StandardError` rescue swallowing a matched throw.
+//!
+//! Note that `catch`/`throw` are not yet bound as `Kernel#catch`/
+//! `Kernel#throw` methods callable from Ruby: that registration belongs to
+//! `Kernel`'s own module-definition file, which is not part of this tree.
+//! The functions in this module are the VM-facing halves of that binding
+//! and are exercised directly by this module's own tests in the meantime.
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::mem;
+
+use spinoso_exception::UncaughtThrowError;
+
+use crate::exception::Exception;
+use crate::extn::core::exception::Fatal;
+use crate::protect;
+use crate::sys::{self, DescribeState};
+use crate::types::Ruby;
+use crate::value::Value;
+use crate::Artichoke;
+
+/// Identifies a `catch` tag by the identity of the Ruby object backing it,
+/// mirroring MRI's `object_id`/`equal?` comparison rather than `==`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TagId {
+    /// A heap-allocated tag, identified by the pointer backing it.
+    Object(*const c_void),
+    /// An immediate value -- `Symbol`, `Integer`/`Fixnum`, `nil`, or
+    /// `true`/`false` -- identified by its raw `mrb_value` representation
+    /// rather than a pointer, since these values have no heap allocation to
+    /// point at. Two `mrb_value`s for the same immediate always have the
+    /// same representation, so comparing the bytes (never dereferencing
+    /// them as a pointer) is a sound identity check.
+    Immediate([u8; mem::size_of::<sys::mrb_value>()]),
+}
+
+thread_local! {
+    // The stack of tags for currently active `catch` frames, innermost
+    // last. `throw` consults this to decide whether any enclosing `catch`
+    // frame can possibly accept its tag before it pays the cost of an
+    // unwind.
+    static ACTIVE_TAGS: RefCell<Vec<TagId>> = RefCell::new(Vec::new());
+
+    // Set by `throw` immediately before raising its carrier exception, and
+    // taken by the innermost `catch` frame whose tag matches. This lets
+    // `catch` recover the thrown tag/value without needing to downcast the
+    // boxed exception object that unwound through `mrb_protect`.
+    static PENDING_THROW: RefCell<Option<(TagId, sys::mrb_value)>> = RefCell::new(None);
+}
+
+fn tag_id(tag: Value) -> TagId {
+    match tag.ruby_type() {
+        // `mrb_sys_obj_ptr` is only valid for heap-allocated, pointer-backed
+        // values; calling it on an immediate reads that value's bit pattern
+        // as though it were a pointer, which is not a meaningful operation.
+        Ruby::Symbol | Ruby::Integer | Ruby::Fixnum | Ruby::Nil | Ruby::Bool => {
+            // SAFETY: copies the `mrb_value`'s bytes into an equal-sized
+            // array; the result is only ever compared, never dereferenced.
+            TagId::Immediate(unsafe { mem::transmute_copy(&tag.inner()) })
+        }
+        _ => TagId::Object(unsafe { sys::mrb_sys_obj_ptr(tag.inner()) }.cast()),
+    }
+}
+
+/// `Kernel#throw(tag, value = nil)`.
+///
+/// If an active `catch` frame has a matching tag, records `tag`'s identity
+/// and `value` for that frame to claim and raises a [`Fatal`] carrier
+/// exception. Otherwise raises an [`UncaughtThrowError`] whose message
+/// includes `tag`'s `inspect` string.
+pub fn throw(interp: &mut Artichoke, tag: Value, value: Value) -> Result<Value, Exception> {
+    let id = tag_id(tag);
+
+    let has_handler = ACTIVE_TAGS.with(|tags| tags.borrow().contains(&id));
+    if !has_handler {
+        let mut message = b"uncaught throw ".to_vec();
+        message.extend_from_slice(tag.inspect(interp).as_slice());
+        return Err(Exception::from(UncaughtThrowError::from(message)));
+    }
+
+    PENDING_THROW.with(|pending| {
+        *pending.borrow_mut() = Some((id, value.inner()));
+    });
+
+    // See the module doc comment: raised as `Fatal`, not `UncaughtThrowError`,
+    // so an intervening ordinary `rescue` cannot swallow it before it
+    // unwinds to the matching `catch` frame found above.
+    Err(Exception::from(Fatal::new(interp, "uncaught throw")))
+}
+
+/// `Kernel#catch(tag = Object.new) { |tag| ... }`.
+///
+/// Evaluates `block`, passing it `tag`, under a protected call. If `block`
+/// completes normally, its result is returned. If a matching `throw`
+/// unwinds through the protected call, the thrown value is returned instead.
+/// Any other exception -- including a `throw` for a tag that is not this
+/// frame's -- propagates to the caller.
+pub fn catch(interp: &mut Artichoke, tag: Value, block: Value) -> Result<Value, Exception> {
+    let id = tag_id(tag);
+    ACTIVE_TAGS.with(|tags| tags.borrow_mut().push(id));
+
+    let mrb = interp.0.borrow().mrb;
+    trace!("Evaling protected catch block on {}", mrb.debug());
+    // Guarded by the shared `protect` helper rather than a private
+    // `mrb_protect` trampoline of our own.
+    let result = protect::protect(interp, move |interp| {
+        let mrb = interp.0.borrow().mrb;
+        let args = [tag.inner()];
+        let raw = unsafe { sys::mrb_yield_argv(mrb, block.inner(), 1, args.as_ptr()) };
+        Value::new(interp, raw)
+    });
+
+    ACTIVE_TAGS.with(|tags| {
+        tags.borrow_mut().pop();
+    });
+
+    match result {
+        Ok(value) => {
+            // A `throw` targeting this frame's tag may have set
+            // `PENDING_THROW` and then been rescued by ordinary Ruby code
+            // before its `Fatal` carrier unwound out of `block`, in which
+            // case `result` is `Ok` even though `PENDING_THROW` still holds
+            // the resulting stale entry. Left alone, that entry would leak
+            // into a later, unrelated `catch` call for the same tag and be
+            // mistaken for a fresh throw, silently swallowing whatever
+            // exception is actually in flight there. Since any `throw` for
+            // our own tag can only be ours to claim -- if it had escaped to
+            // here it would have taken the `Err` branch below instead -- an
+            // entry matching our id at this point is always stale, so
+            // clear it.
+            PENDING_THROW.with(|pending| {
+                let mut pending = pending.borrow_mut();
+                if matches!(*pending, Some((pending_id, _)) if pending_id == id) {
+                    *pending = None;
+                }
+            });
+            Ok(value)
+        }
+        Err(exc) => {
+            if let Some((pending_id, value)) = PENDING_THROW.with(|pending| pending.borrow_mut().take()) {
+                if pending_id == id {
+                    return Ok(Value::new(interp, value));
+                }
+                // Not our tag: restore it so an enclosing `catch` frame (or
+                // the top level) can see it, then re-raise.
+                PENDING_THROW.with(|pending| *pending.borrow_mut() = Some((pending_id, value)));
+            }
+            Err(exc)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{tag_id, throw, ACTIVE_TAGS, PENDING_THROW};
+    use crate::test::prelude::*;
+
+    // Regression tests for the immediate-value bug in `tag_id`: every
+    // immediate kind (`Symbol`, `Integer`, `nil`, `true`, `false`) must be
+    // identifiable without calling `mrb_sys_obj_ptr` on it.
+    #[test]
+    fn tag_id_is_stable_for_immediates() {
+        let mut interp = interpreter();
+
+        for code in [&b":done"[..], b"1", b"nil", b"true", b"false"] {
+            let a = interp.eval(code).unwrap();
+            let b = interp.eval(code).unwrap();
+            assert_eq!(tag_id(a), tag_id(b), "tag_id for `{}`", String::from_utf8_lossy(code));
+        }
+
+        assert_ne!(tag_id(interp.eval(b"1").unwrap()), tag_id(interp.eval(b"2").unwrap()));
+        assert_ne!(tag_id(interp.eval(b":a").unwrap()), tag_id(interp.eval(b":b").unwrap()));
+        assert_ne!(tag_id(interp.eval(b"nil").unwrap()), tag_id(interp.eval(b"false").unwrap()));
+    }
+
+    #[test]
+    fn tag_id_differs_for_distinct_heap_objects() {
+        let mut interp = interpreter();
+
+        let a = interp.eval(b"Object.new").unwrap();
+        let b = interp.eval(b"Object.new").unwrap();
+        assert_ne!(tag_id(a), tag_id(b));
+    }
+
+    #[test]
+    fn catch_returns_block_result_when_not_thrown() {
+        let mut interp = interpreter();
+
+        let tag = interp.eval(b":done").unwrap();
+        let block = interp.eval(b"proc { 42 }").unwrap();
+        let result = super::catch(&mut interp, tag, block).unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 42);
+    }
+
+    #[test]
+    fn throw_without_matching_catch_frame_is_uncaught_throw_error() {
+        let mut interp = interpreter();
+
+        let tag = interp.eval(b":done").unwrap();
+        let value = interp.eval(b"1").unwrap();
+        let err = throw(&mut interp, tag, value).unwrap_err();
+        assert_eq!(err.name(), "UncaughtThrowError");
+    }
+
+    #[test]
+    fn throw_with_matching_catch_frame_is_not_an_uncaught_throw_error() {
+        let mut interp = interpreter();
+
+        let tag = interp.eval(b":done").unwrap();
+        let value = interp.eval(b"1").unwrap();
+        let id = tag_id(tag);
+
+        // No Ruby-level `Kernel#catch`/`Kernel#throw` binding exists in this
+        // module alone, so a matching `catch` frame is installed directly
+        // rather than via a nested `catch { throw }` eval.
+        ACTIVE_TAGS.with(|tags| tags.borrow_mut().push(id));
+        let err = throw(&mut interp, tag, value).unwrap_err();
+        ACTIVE_TAGS.with(|tags| {
+            tags.borrow_mut().pop();
+        });
+        PENDING_THROW.with(|pending| {
+            pending.borrow_mut().take();
+        });
+
+        assert_ne!(err.name(), "UncaughtThrowError");
+    }
+
+    #[test]
+    fn throw_with_matching_catch_frame_records_pending_throw() {
+        let mut interp = interpreter();
+
+        let tag = interp.eval(b":done").unwrap();
+        let value = interp.eval(b"7").unwrap();
+        let id = tag_id(tag);
+
+        ACTIVE_TAGS.with(|tags| tags.borrow_mut().push(id));
+        let _ = throw(&mut interp, tag, value);
+        ACTIVE_TAGS.with(|tags| {
+            tags.borrow_mut().pop();
+        });
+
+        let pending = PENDING_THROW.with(|pending| pending.borrow_mut().take());
+        let (pending_id, pending_value) = pending.expect("throw records a pending value for a matching tag");
+        assert_eq!(pending_id, id);
+        let pending_value = Value::new(&interp, pending_value);
+        assert_eq!(pending_value.try_convert_into::<i64>(&interp).unwrap(), 7);
+    }
+
+    // Regression test for a `PENDING_THROW` leak: if a `throw`'s `Fatal`
+    // carrier is rescued by ordinary Ruby code before it unwinds out of a
+    // `catch`'s protected block, `catch`'s own protected call returns `Ok`
+    // even though `PENDING_THROW` still holds the resulting stale entry.
+    // Left uncleared, that entry would be mistaken for a fresh throw by the
+    // next, unrelated `catch` call for the same tag. This simulates the
+    // "rescued before it reached catch" case directly, since this module
+    // alone has no Ruby-level `catch`/`rescue` binding to drive it with a
+    // real nested eval.
+    #[test]
+    fn catch_clears_a_pending_throw_left_over_from_a_rescued_carrier() {
+        let mut interp = interpreter();
+
+        let tag = interp.eval(b":done").unwrap();
+        let id = tag_id(tag);
+
+        // Simulate a throw for this tag whose carrier got rescued internally
+        // instead of unwinding out to `catch`.
+        PENDING_THROW.with(|pending| {
+            *pending.borrow_mut() = Some((id, interp.eval(b"1").unwrap().inner()));
+        });
+
+        let block = interp.eval(b"proc { 42 }").unwrap();
+        let result = super::catch(&mut interp, tag, block).unwrap();
+        assert_eq!(result.try_convert_into::<i64>(&interp).unwrap(), 42);
+
+        let leftover_present = PENDING_THROW.with(|pending| pending.borrow().is_some());
+        assert!(!leftover_present, "stale PENDING_THROW entry must not survive a successful catch");
+    }
+}
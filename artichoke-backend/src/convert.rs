@@ -177,3 +177,22 @@ impl From<BoxIntoRubyError> for Error {
         Self::from(err)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{BoxIntoRubyError, UnboxRubyError};
+    use crate::error::Error;
+    use crate::types::{Ruby, Rust};
+
+    #[test]
+    fn unbox_ruby_error_downcasts_out_of_a_boxed_error() {
+        let err = Error::from(UnboxRubyError {
+            from: Ruby::Fixnum,
+            into: Rust::String,
+        });
+        let unboxed = err.downcast_ref::<UnboxRubyError>().expect("boxed exception is UnboxRubyError");
+        assert_eq!(unboxed.from, Ruby::Fixnum);
+        assert_eq!(unboxed.into, Rust::String);
+        assert!(err.downcast_ref::<BoxIntoRubyError>().is_none());
+    }
+}
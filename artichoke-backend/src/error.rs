@@ -0,0 +1,277 @@
+//! A boxed, dynamically-dispatched Ruby exception, plus the trait used to
+//! construct one.
+//!
+//! [`Error`] is the error type returned by Rust-backed implementations of
+//! Ruby methods throughout this crate. It owns a [`RubyException`] trait
+//! object so that conversion failures, argument errors, and the rest can all
+//! be raised without each call site needing to know the concrete exception
+//! type.
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A Ruby exception that can be boxed into an [`Error`] and, from there,
+/// raised into the VM.
+///
+/// [`cause`] and [`as_any`] both have defaults suitable for an exception
+/// that is always constructed fresh (never wraps another in-flight
+/// exception itself) and never needs to be recovered by concrete type:
+/// most `RubyException` implementors do not need to override either.
+///
+/// [`cause`]: Self::cause
+/// [`as_any`]: Self::as_any
+pub trait RubyException: std::error::Error + 'static {
+    /// The message this exception carries, as would be returned by
+    /// `Exception#message`.
+    fn message(&self) -> std::borrow::Cow<'_, [u8]>;
+
+    /// This exception's class name, as would be returned by
+    /// `Exception#class.name`.
+    fn name(&self) -> std::borrow::Cow<'_, str>;
+
+    /// This exception's Ruby-level backtrace, if any has been captured.
+    fn vm_backtrace(&self, interp: &mut crate::Artichoke) -> Option<Vec<Vec<u8>>>;
+
+    /// Box this exception up as a Ruby object that can be raised into the
+    /// VM.
+    fn as_mrb_value(&self, interp: &mut crate::Artichoke) -> Option<crate::sys::mrb_value>;
+
+    /// The exception that was active (e.g. being rescued) when this
+    /// exception was raised, if any, mirroring `Exception#cause`.
+    ///
+    /// This is distinct from [`Error::cause`], which reports only the cause
+    /// recorded automatically by [`ActiveExceptionGuard`] and does not
+    /// consult this method: an implementor can override this to expose a
+    /// cause it tracks itself (for example, one supplied explicitly by a
+    /// caller).
+    #[must_use]
+    fn cause(&self) -> Option<&dyn RubyException> {
+        None
+    }
+
+    /// Type-erase this exception so it can be recovered later with
+    /// [`Error::downcast_ref`], mirroring `Box<dyn Error>::downcast_ref`
+    /// from `std`.
+    #[must_use]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A boxed Ruby exception, together with the exception (if any) that was
+/// active when this one was raised.
+///
+/// `Error` is the error type most Rust-backed implementations of Ruby
+/// methods in this crate return.
+pub struct Error {
+    exc: Box<dyn RubyException>,
+    cause: Option<Rc<Error>>,
+}
+
+impl Error {
+    /// Box `exc` into an `Error`, automatically recording the
+    /// currently-active exception (see [`ActiveExceptionGuard`]) as its
+    /// cause, the way MRI's `raise` records the exception being rescued.
+    #[must_use]
+    pub fn new(exc: Box<dyn RubyException>) -> Self {
+        Self {
+            exc,
+            cause: currently_active_exception(),
+        }
+    }
+
+    /// The message of the boxed exception.
+    #[must_use]
+    pub fn message(&self) -> std::borrow::Cow<'_, [u8]> {
+        self.exc.message()
+    }
+
+    /// The class name of the boxed exception.
+    #[must_use]
+    pub fn name(&self) -> std::borrow::Cow<'_, str> {
+        self.exc.name()
+    }
+
+    /// The exception that was active when this one was raised, if any.
+    ///
+    /// This is automatically recorded by [`Error::new`] from whichever
+    /// [`ActiveExceptionGuard`] is innermost at construction time; it is not
+    /// something a caller attaches by hand.
+    #[must_use]
+    pub fn cause(&self) -> Option<&Error> {
+        self.cause.as_deref()
+    }
+
+    /// Recover a reference to the concrete exception type that was boxed
+    /// into this `Error`, if it matches `T`.
+    #[must_use]
+    pub fn downcast_ref<T: RubyException>(&self) -> Option<&T> {
+        self.exc.as_any().downcast_ref::<T>()
+    }
+}
+
+impl From<Box<dyn RubyException>> for Error {
+    fn from(exc: Box<dyn RubyException>) -> Self {
+        Self::new(exc)
+    }
+}
+
+thread_local! {
+    // The stack of exceptions currently being handled, innermost last. Each
+    // live `ActiveExceptionGuard` holds one entry, so that any `Error`
+    // constructed while it is alive is chained to that entry via
+    // `Error::cause`. Entries are pushed only by `ActiveExceptionGuard::enter`;
+    // running a Ruby-level `rescue` clause does not push one.
+    static ACTIVE_EXCEPTION: RefCell<Vec<Rc<Error>>> = RefCell::new(Vec::new());
+}
+
+fn currently_active_exception() -> Option<Rc<Error>> {
+    ACTIVE_EXCEPTION.with(|stack| stack.borrow().last().cloned())
+}
+
+/// Marks `exc` as the exception currently being rescued for the lifetime of
+/// the guard, so any `Error` constructed (via [`Error::new`]) while the
+/// guard is alive automatically records `exc` as its cause.
+///
+/// A guard exists only where Rust code calls [`ActiveExceptionGuard::enter`]:
+/// a Ruby-level `rescue` clause does not enter one, so a `raise` inside a
+/// `rescue` body records no cause this way. Dropping the guard restores
+/// whichever exception (if any) was active before it, so nested guards chain
+/// correctly.
+#[must_use]
+pub struct ActiveExceptionGuard(());
+
+impl ActiveExceptionGuard {
+    pub fn enter(exc: Rc<Error>) -> Self {
+        ACTIVE_EXCEPTION.with(|stack| stack.borrow_mut().push(exc));
+        Self(())
+    }
+}
+
+impl Drop for ActiveExceptionGuard {
+    fn drop(&mut self) {
+        ACTIVE_EXCEPTION.with(|stack| {
+            stack.borrow_mut().pop();
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::borrow::Cow;
+    use std::fmt;
+    use std::rc::Rc;
+
+    use super::{ActiveExceptionGuard, Error, RubyException};
+
+    #[derive(Debug)]
+    struct Stub(&'static str);
+
+    impl fmt::Display for Stub {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str(self.0)
+        }
+    }
+
+    impl std::error::Error for Stub {}
+
+    impl RubyException for Stub {
+        fn message(&self) -> Cow<'_, [u8]> {
+            Cow::Borrowed(self.0.as_bytes())
+        }
+
+        fn name(&self) -> Cow<'_, str> {
+            Cow::Borrowed("StubError")
+        }
+
+        fn vm_backtrace(&self, _interp: &mut crate::Artichoke) -> Option<Vec<Vec<u8>>> {
+            None
+        }
+
+        fn as_mrb_value(&self, _interp: &mut crate::Artichoke) -> Option<crate::sys::mrb_value> {
+            None
+        }
+    }
+
+    #[test]
+    fn error_has_no_cause_without_an_active_exception_guard() {
+        let err = Error::new(Box::new(Stub("no cause")));
+        assert!(err.cause().is_none());
+    }
+
+    #[test]
+    fn error_raised_while_a_guard_is_active_records_its_cause() {
+        let first = Rc::new(Error::new(Box::new(Stub("first"))));
+        let guard = ActiveExceptionGuard::enter(Rc::clone(&first));
+
+        let second = Error::new(Box::new(Stub("second")));
+        assert_eq!(second.cause().unwrap().message(), first.message());
+
+        drop(guard);
+
+        // Once the guard protecting `first` is dropped, a new `Error` no
+        // longer picks it up as a cause.
+        let third = Error::new(Box::new(Stub("third")));
+        assert!(third.cause().is_none());
+    }
+
+    #[test]
+    fn nested_guards_chain_to_the_innermost_active_exception() {
+        let outer = Rc::new(Error::new(Box::new(Stub("outer"))));
+        let _outer_guard = ActiveExceptionGuard::enter(Rc::clone(&outer));
+
+        let inner = Rc::new(Error::new(Box::new(Stub("inner"))));
+        assert_eq!(inner.cause().unwrap().message(), outer.message());
+        let inner_guard = ActiveExceptionGuard::enter(Rc::clone(&inner));
+
+        let raised = Error::new(Box::new(Stub("raised")));
+        assert_eq!(raised.cause().unwrap().message(), inner.message());
+
+        drop(inner_guard);
+        let after_inner_guard = Error::new(Box::new(Stub("after")));
+        assert_eq!(after_inner_guard.cause().unwrap().message(), outer.message());
+    }
+
+    #[test]
+    fn downcast_ref_recovers_the_concrete_exception_type() {
+        let err = Error::new(Box::new(Stub("boom")));
+        let stub = err.downcast_ref::<Stub>().expect("boxed exception is a Stub");
+        assert_eq!(stub.0, "boom");
+    }
+
+    #[derive(Debug)]
+    struct OtherStub;
+
+    impl fmt::Display for OtherStub {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("other")
+        }
+    }
+
+    impl std::error::Error for OtherStub {}
+
+    impl RubyException for OtherStub {
+        fn message(&self) -> Cow<'_, [u8]> {
+            Cow::Borrowed(b"other")
+        }
+
+        fn name(&self) -> Cow<'_, str> {
+            Cow::Borrowed("OtherStubError")
+        }
+
+        fn vm_backtrace(&self, _interp: &mut crate::Artichoke) -> Option<Vec<Vec<u8>>> {
+            None
+        }
+
+        fn as_mrb_value(&self, _interp: &mut crate::Artichoke) -> Option<crate::sys::mrb_value> {
+            None
+        }
+    }
+
+    #[test]
+    fn downcast_ref_returns_none_for_a_mismatched_type() {
+        let err = Error::new(Box::new(Stub("boom")));
+        assert!(err.downcast_ref::<OtherStub>().is_none());
+    }
+}
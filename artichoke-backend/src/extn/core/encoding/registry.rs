@@ -0,0 +1,128 @@
+use std::sync::Mutex;
+
+use crate::extn::core::string::Encoding as SpinosoEncoding;
+
+/// Static metadata backing the `Encoding` class method registry.
+///
+/// Artichoke only ships a handful of encodings, so rather than modeling the
+/// full IANA charset registry, each supported [`SpinosoEncoding`] is paired
+/// with its MRI-facing primary name and alias list here.
+#[derive(Debug, Clone, Copy)]
+pub struct EncodingEntry {
+    pub encoding: SpinosoEncoding,
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub ascii_compatible: bool,
+    pub dummy: bool,
+}
+
+impl EncodingEntry {
+    /// The primary name followed by all aliases, in the order MRI's
+    /// `Encoding#names` returns them.
+    #[must_use]
+    pub fn names(&self) -> impl Iterator<Item = &'static str> {
+        core::iter::once(self.name).chain(self.aliases.iter().copied())
+    }
+}
+
+pub const ENCODINGS: &[EncodingEntry] = &[
+    EncodingEntry {
+        encoding: SpinosoEncoding::Ascii,
+        name: "US-ASCII",
+        aliases: &["ASCII", "ANSI_X3.4-1968", "646"],
+        ascii_compatible: true,
+        dummy: false,
+    },
+    EncodingEntry {
+        encoding: SpinosoEncoding::Utf8,
+        name: "UTF-8",
+        aliases: &["CP65001"],
+        ascii_compatible: true,
+        dummy: false,
+    },
+    EncodingEntry {
+        encoding: SpinosoEncoding::Binary,
+        name: "ASCII-8BIT",
+        aliases: &["BINARY"],
+        ascii_compatible: true,
+        dummy: false,
+    },
+];
+
+/// Resolve a name or alias (case-insensitively, as MRI does) to its registry
+/// entry.
+#[must_use]
+pub fn find(name: &[u8]) -> Option<&'static EncodingEntry> {
+    let name = core::str::from_utf8(name).ok()?;
+    if let Some(entry) = ENCODINGS
+        .iter()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name) || entry.aliases.iter().any(|alias| alias.eq_ignore_ascii_case(name)))
+    {
+        return Some(entry);
+    }
+    REPLICAS
+        .lock()
+        .expect("replica registry lock poisoned")
+        .iter()
+        .copied()
+        .find(|entry| entry.name.eq_ignore_ascii_case(name))
+}
+
+/// Look up the registry entry for a concrete [`SpinosoEncoding`].
+///
+/// Every variant of `SpinosoEncoding` has a corresponding entry in
+/// [`ENCODINGS`], so this never fails.
+///
+/// Note this always resolves to the *original* static entry for `encoding`,
+/// never to a [`register_replica`]-created one, even if `encoding` is the
+/// replica's own underlying encoding: a replica has no identity beyond the
+/// `SpinosoEncoding` it copies, since `Encoding`'s boxed value only stores
+/// that, not which registry entry produced it.
+#[must_use]
+pub fn entry_for(encoding: SpinosoEncoding) -> &'static EncodingEntry {
+    ENCODINGS
+        .iter()
+        .find(|entry| entry.encoding == encoding)
+        .expect("every `SpinosoEncoding` has a registry entry")
+}
+
+/// Dynamically-registered aliases created by `Encoding#replicate`, kept
+/// separate from the static [`ENCODINGS`] table since they are process-local,
+/// mutable state rather than something fixed at compile time.
+static REPLICAS: Mutex<Vec<&'static EncodingEntry>> = Mutex::new(Vec::new());
+
+/// A name passed to [`register_replica`] that is already a known encoding
+/// name, alias, or previously-registered replica name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplicaNameConflict;
+
+/// Register `name` as a new `dummy` alias for `encoding`, as created by
+/// `Encoding#replicate`.
+///
+/// The returned entry is leaked for the remaining lifetime of the process:
+/// like the static `ENCODINGS` table, a replica is meant to stay visible for
+/// as long as the interpreter that created it runs, and an `Encoding`
+/// object has no destructor to reclaim it at.
+pub fn register_replica(name: &str, encoding: SpinosoEncoding) -> Result<&'static EncodingEntry, ReplicaNameConflict> {
+    if find(name.as_bytes()).is_some() {
+        return Err(ReplicaNameConflict);
+    }
+    let entry: &'static EncodingEntry = Box::leak(Box::new(EncodingEntry {
+        encoding,
+        name: Box::leak(name.to_string().into_boxed_str()),
+        aliases: &[],
+        ascii_compatible: entry_for(encoding).ascii_compatible,
+        dummy: true,
+    }));
+    REPLICAS
+        .lock()
+        .expect("replica registry lock poisoned")
+        .push(entry);
+    Ok(entry)
+}
+
+/// A snapshot of every replica registered so far via [`register_replica`].
+#[must_use]
+pub fn replicas() -> Vec<&'static EncodingEntry> {
+    REPLICAS.lock().expect("replica registry lock poisoned").clone()
+}
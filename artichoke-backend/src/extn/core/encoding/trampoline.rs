@@ -1,30 +1,94 @@
+use super::registry::{self, EncodingEntry};
 use super::Encoding;
 
 use crate::extn::core::string::{Encoding as SpinosoEncoding, String};
 
 use crate::extn::prelude::*;
 
+fn encoding_instance(interp: &mut Artichoke, entry: &EncodingEntry) -> Result<Value, Error> {
+    Encoding::alloc_value(entry.encoding, interp)
+}
+
+fn ascii_string(interp: &mut Artichoke, name: &str) -> Result<Value, Error> {
+    // Encoding names are always 7bit ascii, see the comment in `name` below.
+    let s = String::with_bytes_and_encoding(name.as_bytes().to_vec(), SpinosoEncoding::Ascii);
+    String::alloc_value(s, interp)
+}
+
 pub fn aliases(interp: &mut Artichoke) -> Result<Value, Error> {
-    let _ = interp;
-    Err(NotImplementedError::new().into())
+    let mut pairs = Vec::with_capacity(registry::ENCODINGS.iter().map(|entry| entry.aliases.len()).sum());
+    for entry in registry::ENCODINGS {
+        for alias in entry.aliases {
+            let key = ascii_string(interp, alias)?;
+            let value = ascii_string(interp, entry.name)?;
+            pairs.push((key, value));
+        }
+    }
+    // A replica created by `Encoding#replicate` registers its own name as
+    // an alias pointing back at the encoding it replicated, the same way a
+    // static entry's aliases point back at its own primary name.
+    for entry in registry::replicas() {
+        let key = ascii_string(interp, entry.name)?;
+        let value = ascii_string(interp, registry::entry_for(entry.encoding).name)?;
+        pairs.push((key, value));
+    }
+    interp.try_convert_mut(pairs)
 }
 
-pub fn compatible(interp: &mut Artichoke, lhs: Value, rhs: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = lhs;
-    let _ = rhs;
-    Err(NotImplementedError::new().into())
+pub fn compatible(interp: &mut Artichoke, mut lhs: Value, mut rhs: Value) -> Result<Value, Error> {
+    let left = unsafe { String::unbox_from_value(&mut lhs, interp)? };
+    let right = unsafe { String::unbox_from_value(&mut rhs, interp)? };
+
+    let left_encoding = left.encoding();
+    let right_encoding = right.encoding();
+
+    // MRI: two strings are compatible if their encodings are equal, or if
+    // one side is 7-bit ASCII-only and the other side's encoding is
+    // ASCII-compatible.
+    let compatible = if left_encoding == right_encoding {
+        Some(left_encoding)
+    } else {
+        let left_is_ascii_only = left.as_slice().is_ascii();
+        let right_is_ascii_only = right.as_slice().is_ascii();
+        let left_entry = registry::entry_for(left_encoding);
+        let right_entry = registry::entry_for(right_encoding);
+
+        if right_is_ascii_only && left_entry.ascii_compatible {
+            Some(left_encoding)
+        } else if left_is_ascii_only && right_entry.ascii_compatible {
+            Some(right_encoding)
+        } else {
+            None
+        }
+    };
+
+    match compatible {
+        Some(encoding) => encoding_instance(interp, registry::entry_for(encoding)),
+        None => Ok(interp.convert(None::<Value>)),
+    }
 }
 
-pub fn find(interp: &mut Artichoke, encoding: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    Err(NotImplementedError::new().into())
+pub fn find(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
+    let name = implicitly_convert_to_string(interp, &mut encoding)?;
+    if let Some(entry) = registry::find(name) {
+        encoding_instance(interp, entry)
+    } else {
+        let mut message = b"unknown encoding name - ".to_vec();
+        message.extend_from_slice(name);
+        Err(ArgumentError::from(message).into())
+    }
 }
 
 pub fn list(interp: &mut Artichoke) -> Result<Value, Error> {
-    let _ = interp;
-    Err(NotImplementedError::new().into())
+    let replicas = registry::replicas();
+    let mut encodings = Vec::with_capacity(registry::ENCODINGS.len() + replicas.len());
+    for entry in registry::ENCODINGS {
+        encodings.push(encoding_instance(interp, entry)?);
+    }
+    for entry in &replicas {
+        encodings.push(encoding_instance(interp, entry)?);
+    }
+    interp.try_convert_mut(encodings)
 }
 
 pub fn locale_charmap(interp: &mut Artichoke) -> Result<Value, Error> {
@@ -33,26 +97,42 @@ pub fn locale_charmap(interp: &mut Artichoke) -> Result<Value, Error> {
 }
 
 pub fn name_list(interp: &mut Artichoke) -> Result<Value, Error> {
-    let _ = interp;
-    Err(NotImplementedError::new().into())
+    let replicas = registry::replicas();
+    let mut names = Vec::with_capacity(registry::ENCODINGS.len() + replicas.len());
+    for entry in registry::ENCODINGS {
+        names.push(ascii_string(interp, entry.name)?);
+    }
+    for entry in &replicas {
+        names.push(ascii_string(interp, entry.name)?);
+    }
+    interp.try_convert_mut(names)
 }
 
-pub fn ascii_compatible(interp: &mut Artichoke, encoding: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    Err(NotImplementedError::new().into())
+pub fn ascii_compatible(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
+    let encoding = unsafe { Encoding::unbox_from_value(&mut encoding, interp)? };
+    let entry = registry::entry_for(*encoding);
+    Ok(interp.convert(entry.ascii_compatible))
 }
 
-pub fn dummy(interp: &mut Artichoke, encoding: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    Err(NotImplementedError::new().into())
+pub fn dummy(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
+    let encoding = unsafe { Encoding::unbox_from_value(&mut encoding, interp)? };
+    let entry = registry::entry_for(*encoding);
+    Ok(interp.convert(entry.dummy))
 }
 
-pub fn inspect(interp: &mut Artichoke, encoding: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    Err(NotImplementedError::new().into())
+pub fn inspect(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
+    let encoding = unsafe { Encoding::unbox_from_value(&mut encoding, interp)? };
+    let entry = registry::entry_for(*encoding);
+
+    let mut inspect = b"#<Encoding:".to_vec();
+    inspect.extend_from_slice(entry.name.as_bytes());
+    if entry.dummy {
+        inspect.extend_from_slice(b" (dummy)");
+    }
+    inspect.push(b'>');
+
+    let s = String::with_bytes_and_encoding(inspect, SpinosoEncoding::Ascii);
+    String::alloc_value(s, interp)
 }
 
 pub fn name(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
@@ -71,15 +151,45 @@ pub fn name(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error>
     String::alloc_value(result, interp)
 }
 
-pub fn names(interp: &mut Artichoke, encoding: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    Err(NotImplementedError::new().into())
+pub fn names(interp: &mut Artichoke, mut encoding: Value) -> Result<Value, Error> {
+    let encoding = unsafe { Encoding::unbox_from_value(&mut encoding, interp)? };
+    let entry = registry::entry_for(*encoding);
+
+    let mut names = Vec::new();
+    for name in entry.names() {
+        names.push(ascii_string(interp, name)?);
+    }
+    interp.try_convert_mut(names)
 }
 
-pub fn replicate(interp: &mut Artichoke, encoding: Value, target: Value) -> Result<Value, Error> {
-    let _ = interp;
-    let _ = encoding;
-    let _ = target;
-    Err(NotImplementedError::new().into())
+pub fn replicate(interp: &mut Artichoke, mut encoding: Value, mut target: Value) -> Result<Value, Error> {
+    let encoding = *unsafe { Encoding::unbox_from_value(&mut encoding, interp)? };
+    let name = implicitly_convert_to_string(interp, &mut target)?;
+    let name = core::str::from_utf8(name).map_err(|_| {
+        let mut message = b"invalid encoding name - ".to_vec();
+        message.extend_from_slice(name);
+        ArgumentError::from(message)
+    })?;
+
+    // `registry::ENCODINGS` is a fixed, static table, so a replica is kept
+    // in a separate, dynamic registry (see `registry::register_replica`)
+    // rather than durably merged into it.
+    //
+    // The `Encoding` instance returned below still only carries `encoding`
+    // -- it has no way to remember that it came from *this* replica entry
+    // rather than the original one `encoding` was looked up from -- so its
+    // own `name`/`dummy?`/`inspect` report the original entry's identity,
+    // not the replica's. Fixing that needs `Encoding`'s own boxed
+    // representation (`extn::core::encoding::Encoding`, not part of this
+    // tree) to carry a registry-entry identity instead of a bare
+    // `SpinosoEncoding`. `Encoding.find`, `.name_list`, and `.aliases` are
+    // unaffected by this gap and correctly see the new replica.
+    match registry::register_replica(name, encoding) {
+        Ok(entry) => encoding_instance(interp, entry),
+        Err(registry::ReplicaNameConflict) => {
+            let mut message = b"replica name already in use - ".to_vec();
+            message.extend_from_slice(name.as_bytes());
+            Err(ArgumentError::from(message).into())
+        }
+    }
 }
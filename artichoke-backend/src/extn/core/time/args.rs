@@ -1,5 +1,6 @@
 use crate::convert::to_int;
 use crate::extn::prelude::*;
+use crate::types::Ruby;
 
 #[derive(Debug)]
 pub struct TimeArgs {
@@ -10,6 +11,9 @@ pub struct TimeArgs {
     minute: i64,
     second: i64,
     micros: i64,
+    // Set when the seconds argument is a `Float` or `Rational` with
+    // sub-second precision. Takes priority over `micros` when present.
+    subsec_nanos: Option<u32>,
 }
 
 impl Default for TimeArgs {
@@ -22,6 +26,7 @@ impl Default for TimeArgs {
             minute: 0,
             second: 0,
             micros: 0,
+            subsec_nanos: None,
         }
     }
 }
@@ -77,6 +82,12 @@ impl TimeArgs {
     }
 
     pub fn nanoseconds(&self) -> Result<u32, Error> {
+        // A `Float`/`Rational` seconds argument carries its own sub-second
+        // precision and takes priority over an explicit micros argument.
+        if let Some(subsec_nanos) = self.subsec_nanos {
+            return Ok(subsec_nanos);
+        }
+
         // TimeArgs take a micros parameter, not a nanos value. The below
         // multiplication and casting is gauranteed to be inside a `u32`.
         match self.micros {
@@ -87,6 +98,26 @@ impl TimeArgs {
     }
 }
 
+// Split a fractional seconds value into whole seconds and a nanosecond
+// remainder (rounded half-to-even), matching MRI's handling of a
+// `Float`/`Rational` seconds argument to `Time.at`/`Time.new`. The whole part
+// is floored, not truncated toward zero, so a negative fractional seconds
+// value (e.g. `-1.5`) normalizes to a non-negative nanosecond remainder
+// (`-2` seconds, `500_000_000` nanos) instead of borrowing nothing and
+// clamping a negative remainder to zero.
+fn split_fractional_seconds(seconds: f64) -> Result<(i64, u32), Error> {
+    #![allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let whole = seconds.floor();
+    let frac = (seconds - whole) * 1_000_000_000.0;
+    // `f64::round_ties_even` rounds half-to-even, matching MRI's handling of
+    // exact-half nanosecond remainders.
+    let nanos = frac.round_ties_even();
+    match nanos as u32 {
+        nanos @ 0..=999_999_999 => Ok((whole as i64, nanos)),
+        _ => Err(ArgumentError::with_message("subsecx out of range").into()),
+    }
+}
+
 pub fn as_time_args(interp: &mut Artichoke, args: &[Value]) -> Result<TimeArgs, Error> {
     // TimeArgs are in order of year, month, day, hour, minute, second, micros.
     // This is unless there are 10 arguments provided (`Time#to_a` format), at
@@ -110,6 +141,33 @@ pub fn as_time_args(interp: &mut Artichoke, args: &[Value]) -> Result<TimeArgs,
                     continue;
                 }
 
+                // The seconds argument may be a `Float`/`Rational` carrying
+                // sub-second precision rather than an `Integer`. `Rational`
+                // has no dedicated Ruby type here, so it is recognized by
+                // coercing through `#to_f`, the same path MRI uses to fold
+                // any `Numeric` into a `Float` seconds value.
+                if i == 5 {
+                    let seconds = match arg.ruby_type() {
+                        Ruby::Float => Some(interp.try_convert_mut::<Value, f64>(*arg)?),
+                        Ruby::Integer | Ruby::Fixnum => None,
+                        _ => arg.funcall::<f64>(interp, "to_f", &[], None).ok(),
+                    };
+                    if let Some(seconds) = seconds {
+                        let (whole, nanos) = split_fractional_seconds(seconds)?;
+                        result.second = whole;
+                        if nanos != 0 {
+                            result.subsec_nanos = Some(nanos);
+                        }
+                        continue;
+                    }
+                }
+
+                // An explicit micros argument is ignored when the seconds
+                // argument already carried sub-second precision.
+                if i == 6 && result.subsec_nanos.is_some() {
+                    continue;
+                }
+
                 let arg = to_int(interp, *arg)?;
                 // unwrap is safe since to_int gaurnatees a non nil Ruby::Integer
                 let arg: i64 = arg.try_convert_into::<Option<i64>>(interp)?.unwrap();
@@ -136,7 +194,29 @@ pub fn as_time_args(interp: &mut Artichoke, args: &[Value]) -> Result<TimeArgs,
             }
             Ok(result)
         }
-        10 => todo!(),
+        10 => {
+            // `Time#to_a` order: `[sec, min, hour, mday, mon, year, wday,
+            // yday, isdst, zone]`. `wday` and `yday` are derived fields and
+            // `isdst`/`zone` are informational only, so only the first six
+            // entries are converted and used.
+            let mut result = TimeArgs::default();
+            for (i, arg) in args.iter().enumerate().take(6) {
+                let arg = to_int(interp, *arg)?;
+                // unwrap is safe since to_int gaurnatees a non nil Ruby::Integer
+                let arg: i64 = arg.try_convert_into::<Option<i64>>(interp)?.unwrap();
+
+                match i {
+                    0 => result.second = arg,
+                    1 => result.minute = arg,
+                    2 => result.hour = arg,
+                    3 => result.day = arg,
+                    4 => result.month = arg,
+                    5 => result.year = arg,
+                    _ => unreachable!(),
+                }
+            }
+            Ok(result)
+        }
         _ => unreachable!(),
     }
 }
@@ -328,7 +408,48 @@ mod tests {
     }
 
     #[test]
-    fn fractional_seconds_return_nanos() {}
+    fn fractional_seconds_return_nanos() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, 0.5]").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(0, result.second().unwrap());
+        assert_eq!(500_000_000, result.nanoseconds().unwrap());
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, 1.25]").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(1, result.second().unwrap());
+        assert_eq!(250_000_000, result.nanoseconds().unwrap());
+    }
+
+    #[test]
+    fn fractional_seconds_normalizes_negative_remainder() {
+        let mut interp = interpreter();
+
+        // MRI normalizes a negative fractional seconds value by borrowing
+        // from the whole-seconds part rather than rejecting it or producing
+        // a negative nanosecond remainder: `-1.5` is one and a half seconds
+        // before the start of the given minute, i.e. second `-2` plus
+        // `500_000_000` nanos.
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, -1.5]").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(-2, result.second);
+        assert_eq!(500_000_000, result.nanoseconds().unwrap());
+    }
+
+    #[test]
+    fn fractional_seconds_ignore_explicit_micros() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 1, 1, 0, 0, 0.5, 1]").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(0, result.second().unwrap());
+        assert_eq!(500_000_000, result.nanoseconds().unwrap());
+    }
 
     #[test]
     fn nine_args_not_supported() {
@@ -349,10 +470,53 @@ mod tests {
     }
 
     #[test]
-    fn ten_args_changes_unit_order() {}
+    fn ten_args_changes_unit_order() {
+        let mut interp = interpreter();
+
+        // `Time#to_a` order: `[sec, min, hour, mday, mon, year, wday, yday,
+        // isdst, zone]`.
+        let args = interp.eval(b"[6, 5, 4, 3, 2, 2022, 4, 33, false, 'UTC']").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(2022, result.year().unwrap());
+        assert_eq!(2, result.month().unwrap());
+        assert_eq!(3, result.day().unwrap());
+        assert_eq!(4, result.hour().unwrap());
+        assert_eq!(5, result.minute().unwrap());
+        assert_eq!(6, result.second().unwrap());
+    }
 
     #[test]
-    fn ten_args_removes_micros() {}
+    fn ten_args_removes_micros() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[6, 5, 4, 3, 2, 2022, 4, 33, false, 'UTC']").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let result = as_time_args(&mut interp, &ary_args).unwrap();
+        assert_eq!(0, result.nanoseconds().unwrap());
+    }
+
+    #[test]
+    fn ten_args_round_trips_to_a_order() {
+        let mut interp = interpreter();
+
+        let args = interp.eval(b"[2022, 2, 3, 4, 5, 6]").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(args).unwrap();
+        let original = as_time_args(&mut interp, &ary_args).unwrap();
+
+        // Simulate `Time.utc(*t.to_a)`: `to_a` emits fields in reverse order
+        // with `wday`/`yday`/`isdst`/`zone` appended.
+        let to_a = interp.eval(b"[6, 5, 4, 3, 2, 2022, 4, 34, false, 'UTC']").unwrap();
+        let ary_args: Vec<Value> = interp.try_convert_mut(to_a).unwrap();
+        let round_tripped = as_time_args(&mut interp, &ary_args).unwrap();
+
+        assert_eq!(original.year().unwrap(), round_tripped.year().unwrap());
+        assert_eq!(original.month().unwrap(), round_tripped.month().unwrap());
+        assert_eq!(original.day().unwrap(), round_tripped.day().unwrap());
+        assert_eq!(original.hour().unwrap(), round_tripped.hour().unwrap());
+        assert_eq!(original.minute().unwrap(), round_tripped.minute().unwrap());
+        assert_eq!(original.second().unwrap(), round_tripped.second().unwrap());
+    }
 
     #[test]
     fn eleven_args_is_too_many() {
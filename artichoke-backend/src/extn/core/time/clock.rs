@@ -0,0 +1,120 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Identifies which clock source a `Process.clock_gettime` call should read
+/// from.
+///
+/// Corresponds to the `Process::CLOCK_MONOTONIC`, `Process::CLOCK_MONOTONIC_RAW`,
+/// and `Process::CLOCK_REALTIME` constants.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockId {
+    Monotonic,
+    MonotonicRaw,
+    Realtime,
+}
+
+/// Resolution requested from a clock read, trading precision for speed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClockResolution {
+    Fine,
+    Coarse,
+}
+
+/// A monotonic clock anchored at the instant it is constructed.
+///
+/// The interpreter does not construct one itself; each `MonotonicClock`
+/// measures from its own [`MonotonicClock::new`] call.
+///
+/// Readings are nanoseconds elapsed since the clock was constructed and are
+/// guaranteed to never go backwards across successive reads: each read is
+/// clamped against the last observed value, which protects callers even if
+/// the underlying OS monotonic source is not strictly monotonic (a
+/// possibility on some platforms/VMs).
+#[derive(Debug)]
+pub struct MonotonicClock {
+    epoch: Instant,
+    last_nanos: AtomicU64,
+}
+
+impl MonotonicClock {
+    /// Anchor a new monotonic clock at the current instant.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            epoch: Instant::now(),
+            last_nanos: AtomicU64::new(0),
+        }
+    }
+
+    /// Nanoseconds elapsed since this clock was constructed, for the given
+    /// clock id and resolution.
+    ///
+    /// `CLOCK_REALTIME` reads the wall clock directly and is not anchored to
+    /// this clock's epoch, so it is not clamped to be non-decreasing;
+    /// `CLOCK_MONOTONIC`/`CLOCK_MONOTONIC_RAW` reads are.
+    #[must_use]
+    pub fn nanoseconds(&self, id: ClockId, resolution: ClockResolution) -> u64 {
+        match id {
+            ClockId::Realtime => {
+                #![allow(clippy::cast_possible_truncation)]
+                let elapsed = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+                elapsed.as_nanos() as u64
+            }
+            ClockId::Monotonic | ClockId::MonotonicRaw => {
+                #![allow(clippy::cast_possible_truncation)]
+                let elapsed = self.sample(resolution);
+                let nanos = elapsed.as_nanos() as u64;
+                self.last_nanos.fetch_max(nanos, Ordering::AcqRel);
+                self.last_nanos.load(Ordering::Acquire)
+            }
+        }
+    }
+
+    /// Elapsed seconds (as a float) for the given clock id and resolution.
+    #[must_use]
+    pub fn seconds(&self, id: ClockId, resolution: ClockResolution) -> f64 {
+        #![allow(clippy::cast_precision_loss)]
+        self.nanoseconds(id, resolution) as f64 / 1_000_000_000.0
+    }
+
+    fn sample(&self, resolution: ClockResolution) -> Duration {
+        match resolution {
+            // `std` has no portable coarse monotonic clock, so both
+            // resolutions currently read the same `Instant` source; the
+            // distinction exists so callers can express their intent and a
+            // cheaper backing source can be swapped in later without
+            // changing call sites.
+            ClockResolution::Fine | ClockResolution::Coarse => self.epoch.elapsed(),
+        }
+    }
+}
+
+impl Default for MonotonicClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ClockId, ClockResolution, MonotonicClock};
+
+    #[test]
+    fn monotonic_reads_never_go_backwards() {
+        let clock = MonotonicClock::new();
+        let mut last = clock.nanoseconds(ClockId::Monotonic, ClockResolution::Fine);
+        for _ in 0..1000 {
+            let next = clock.nanoseconds(ClockId::Monotonic, ClockResolution::Fine);
+            assert!(next >= last);
+            last = next;
+        }
+    }
+
+    #[test]
+    fn seconds_matches_nanoseconds() {
+        let clock = MonotonicClock::new();
+        let nanos = clock.nanoseconds(ClockId::MonotonicRaw, ClockResolution::Fine);
+        let seconds = clock.seconds(ClockId::MonotonicRaw, ClockResolution::Fine);
+        assert!((seconds - (nanos as f64 / 1_000_000_000.0)).abs() < 1.0);
+    }
+}
@@ -0,0 +1,110 @@
+//! A public, safe `protect`/`ensure` FFI-guard API.
+//!
+//! Every Rust-backed method that calls back into the VM needs longjmp-safe
+//! guarding around `mrb_protect`, so it is exposed here as reusable
+//! infrastructure for extension authors writing native methods;
+//! [`eval::Eval::eval`] uses [`protect`] to guard `mrb_load_nstring_cxt`.
+
+use std::ffi::c_void;
+use std::mem;
+
+use crate::exception::Exception;
+use crate::sys::{self, DescribeState};
+use crate::value::Value;
+use crate::Artichoke;
+
+// `Protect` holds an arbitrary closure, so it is not `Copy`. The call to
+// `mrb_protect` can unwind with `longjmp`, which does not allow Rust to run
+// destructors, so `Protect` is heap-allocated and round-tripped through the
+// `mrb_sys_cptr` `void*`; only the pointer to that allocation needs to
+// survive the jump.
+struct Protect<F> {
+    interp: *const Artichoke,
+    body: F,
+}
+
+impl<F> Protect<F>
+where
+    F: FnOnce(&Artichoke) -> Value,
+{
+    unsafe extern "C" fn run(_mrb: *mut sys::mrb_state, data: sys::mrb_value) -> sys::mrb_value {
+        let ptr = sys::mrb_sys_cptr_ptr(data);
+
+        // Pull the closure and interpreter pointer out of the `Box` so the
+        // heap allocation backing the trampoline's argument is freed before
+        // we call into `body`, which may itself unwind via `longjmp`.
+        let Protect { interp, body } = {
+            let protect = Box::from_raw(ptr as *mut Self);
+            *protect
+        };
+
+        // SAFETY: `interp` is only ever constructed from a live `&Artichoke`
+        // in `protect` below, and the protected call cannot outlive it.
+        let interp = &*interp;
+        body(interp).inner()
+    }
+}
+
+/// Run `body` under an `mrb_protect`-guarded region, capturing any raised
+/// exception into `Err` instead of letting it unwind straight through Rust
+/// stack frames via `longjmp`.
+///
+/// # Critical invariant
+///
+/// `body` is invoked across an FFI boundary that may resume via `longjmp`.
+/// `longjmp` does not run Rust destructors, so nothing `body` captures by
+/// value may rely on its `Drop` implementation running if `body` (or
+/// anything it calls into, such as a method that raises) unwinds. Prefer
+/// capturing only `Copy` state, or state whose leak on the error path is
+/// acceptable.
+pub fn protect<F>(interp: &Artichoke, body: F) -> Result<Value, Exception>
+where
+    F: FnOnce(&Artichoke) -> Value,
+{
+    let mrb = interp.0.borrow().mrb;
+    let protect = Protect {
+        interp: interp as *const Artichoke,
+        body,
+    };
+    trace!("Evaling protected call on {}", mrb.debug());
+    let value = unsafe {
+        let data = sys::mrb_sys_cptr_value(mrb, Box::into_raw(Box::new(protect)) as *mut c_void);
+        let mut state = mem::MaybeUninit::<sys::mrb_bool>::uninit();
+
+        let value = sys::mrb_protect(mrb, Some(Protect::<F>::run), data, state.as_mut_ptr());
+        if state.assume_init() != 0 {
+            (*mrb).exc = sys::mrb_sys_obj_ptr(value);
+        }
+        value
+    };
+
+    if let Some(exc) = interp.last_error()? {
+        Err(exc)
+    } else {
+        Ok(Value::new(interp, value))
+    }
+}
+
+/// Run `body`, then run `cleanup` regardless of whether `body` raised, the
+/// analog of `rb_ensure`.
+///
+/// `cleanup` itself runs under [`protect`]: an exception raised by `cleanup`
+/// takes priority and replaces one raised by `body`, matching MRI's
+/// `ensure` semantics, where a raise in the ensure block supersedes any
+/// in-flight exception.
+pub fn ensure<F, C>(interp: &Artichoke, body: F, cleanup: C) -> Result<Value, Exception>
+where
+    F: FnOnce(&Artichoke) -> Value,
+    C: FnOnce(&Artichoke),
+{
+    let result = protect(interp, body);
+    let cleanup_result = protect(interp, move |interp| {
+        cleanup(interp);
+        interp.convert(None::<Value>)
+    });
+
+    match cleanup_result {
+        Err(exc) => Err(exc),
+        Ok(_) => result,
+    }
+}
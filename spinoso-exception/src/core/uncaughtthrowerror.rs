@@ -1,15 +1,37 @@
+#[cfg(feature = "alloc")]
 use alloc::borrow::Cow;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
 use alloc::string::String;
+#[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 use core::fmt;
 use scolapasta_string_escape::format_debug_escape_into;
 #[cfg(feature = "std")]
 use std::error;
 
+#[cfg(feature = "alloc")]
 use crate::RubyException;
 
 const DEFAULT_MESSAGE: &[u8] = b"UncaughtThrowError";
 
+// The common case -- a class-name-as-message exception -- needs no heap at
+// all, so the borrowed-only representation below requires only `core`.
+// `Cow`/`Box` pull in `alloc`, so the owned-message representation is gated
+// behind the `alloc` feature.
+#[cfg(feature = "alloc")]
+type Message = Cow<'static, [u8]>;
+#[cfg(not(feature = "alloc"))]
+type Message = &'static [u8];
+
+#[cfg(feature = "alloc")]
+type Cause = Option<Box<UncaughtThrowError>>;
+// Without an allocator there is nowhere to own a boxed cause, so an
+// allocator-free `UncaughtThrowError` can only point at a `'static` one.
+#[cfg(not(feature = "alloc"))]
+type Cause = Option<&'static UncaughtThrowError>;
+
 /// Ruby `UncaughtThrowError` error type.
 ///
 /// Descendants of class [`Exception`] are used to communicate between
@@ -24,7 +46,10 @@ const DEFAULT_MESSAGE: &[u8] = b"UncaughtThrowError";
 /// [`NameError#name`]: https://ruby-doc.org/core-2.6.3/NameError.html#method-i-name
 #[derive(Default, Debug, Clone, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UncaughtThrowError {
-    message: Cow<'static, [u8]>,
+    message: Message,
+    // The exception that was active (e.g. being rescued) when this one was
+    // raised, mirroring `Exception#cause`.
+    cause: Cause,
 }
 
 impl UncaughtThrowError {
@@ -41,12 +66,86 @@ impl UncaughtThrowError {
     /// ```
     #[inline]
     #[must_use]
+    #[cfg(feature = "alloc")]
     pub const fn new() -> Self {
         // `Exception` objects initialized via (for example)
         // `raise RuntimeError` or `RuntimeError.new` have `message`
         // equal to the exception's class name.
         let message = Cow::Borrowed(DEFAULT_MESSAGE);
-        Self { message }
+        Self { message, cause: None }
+    }
+
+    /// Construct a new, default `UncaughtThrowError` Ruby exception.
+    ///
+    /// This constructor sets the exception message to `UncaughtThrowError`.
+    #[inline]
+    #[must_use]
+    #[cfg(not(feature = "alloc"))]
+    pub const fn new() -> Self {
+        Self {
+            message: DEFAULT_MESSAGE,
+            cause: None,
+        }
+    }
+
+    /// Return the exception that was active when this exception was raised,
+    /// if any.
+    ///
+    /// This is a manually-attached cause, set via [`with_cause`], not an
+    /// automatically-recorded one: nothing in this crate records the
+    /// currently-active exception when a new one is raised, so a cause only
+    /// appears here if a caller built it with `with_cause` itself. Wiring
+    /// `Exception#cause` to record automatically (the way MRI's `raise`
+    /// does inside an active `rescue`) requires support in `RubyException`
+    /// and the rescue-dispatch path, outside this type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use spinoso_exception::*;
+    /// let exception = UncaughtThrowError::new();
+    /// assert!(exception.cause().is_none());
+    /// let exception = exception.with_cause(UncaughtThrowError::from("first"));
+    /// assert_eq!(exception.cause().unwrap().message(), b"first");
+    /// ```
+    ///
+    /// [`with_cause`]: Self::with_cause
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause.as_deref()
+    }
+
+    /// Return the exception that was active when this exception was raised,
+    /// if any.
+    #[inline]
+    #[must_use]
+    #[cfg(not(feature = "alloc"))]
+    pub fn cause(&self) -> Option<&Self> {
+        self.cause
+    }
+
+    /// Set the exception that was active when this exception was raised.
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn with_cause(mut self, cause: Self) -> Self {
+        self.cause = Some(Box::new(cause));
+        self
+    }
+
+    /// Set the exception that was active when this exception was raised.
+    ///
+    /// Without an allocator there is nowhere to own a boxed cause, so only a
+    /// `'static` cause (for example, one held in a `static` table) can be
+    /// attached.
+    #[inline]
+    #[must_use]
+    #[cfg(not(feature = "alloc"))]
+    pub fn with_cause(mut self, cause: &'static Self) -> Self {
+        self.cause = Some(cause);
+        self
     }
 
     /// Return the message this Ruby exception was constructed with.
@@ -57,6 +156,7 @@ impl UncaughtThrowError {
     /// # use spinoso_exception::*;
     /// let exception = UncaughtThrowError::new();
     /// assert_eq!(exception.message(), b"UncaughtThrowError");
+    ///
     /// let exception = UncaughtThrowError::from("something went wrong");
     /// assert_eq!(exception.message(), b"something went wrong");
     /// ```
@@ -83,22 +183,27 @@ impl UncaughtThrowError {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<String> for UncaughtThrowError {
     #[inline]
     fn from(message: String) -> Self {
         let message = Cow::Owned(message.into_bytes());
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
 impl From<&'static str> for UncaughtThrowError {
     #[inline]
     fn from(message: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
         let message = Cow::Borrowed(message.as_bytes());
-        Self { message }
+        #[cfg(not(feature = "alloc"))]
+        let message = message.as_bytes();
+        Self { message, cause: None }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Cow<'static, str>> for UncaughtThrowError {
     #[inline]
     fn from(message: Cow<'static, str>) -> Self {
@@ -106,30 +211,35 @@ impl From<Cow<'static, str>> for UncaughtThrowError {
             Cow::Borrowed(s) => Cow::Borrowed(s.as_bytes()),
             Cow::Owned(s) => Cow::Owned(s.into_bytes()),
         };
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Vec<u8>> for UncaughtThrowError {
     #[inline]
     fn from(message: Vec<u8>) -> Self {
         let message = Cow::Owned(message);
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
 impl From<&'static [u8]> for UncaughtThrowError {
     #[inline]
     fn from(message: &'static [u8]) -> Self {
+        #[cfg(feature = "alloc")]
         let message = Cow::Borrowed(message);
-        Self { message }
+        #[cfg(not(feature = "alloc"))]
+        let message = message;
+        Self { message, cause: None }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl From<Cow<'static, [u8]>> for UncaughtThrowError {
     #[inline]
     fn from(message: Cow<'static, [u8]>) -> Self {
-        Self { message }
+        Self { message, cause: None }
     }
 }
 
@@ -148,6 +258,9 @@ impl fmt::Display for UncaughtThrowError {
 #[cfg(feature = "std")]
 impl error::Error for UncaughtThrowError {}
 
+// `RubyException` is defined in terms of `Cow`, so implementing it requires
+// `alloc` even when the exception's own message is borrowed.
+#[cfg(feature = "alloc")]
 impl RubyException for UncaughtThrowError {
     #[inline]
     fn message(&self) -> Cow<'_, [u8]> {
@@ -158,4 +271,4 @@ impl RubyException for UncaughtThrowError {
     fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(Self::name(self))
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,214 @@
+use alloc::vec::Vec;
+
+use scolapasta_strbuf::Buf;
+
+use super::BinaryString;
+
+/// A cursor over a borrowed byte slice that supports the incremental reads
+/// needed to implement `String#unpack`.
+///
+/// Every read advances an internal offset and bounds-checks against the end
+/// of the underlying slice. Reads that would run past the end return `None`
+/// rather than panicking or partially advancing the cursor. [`skip`] is the
+/// exception: skipping past the end moves the cursor to the end.
+///
+/// [`skip`]: Self::skip
+#[derive(Debug, Clone, Copy)]
+pub struct Decoder<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Decoder<'a> {
+    /// Construct a new `Decoder` over the given byte slice, positioned at
+    /// the start.
+    #[inline]
+    #[must_use]
+    pub fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// The number of bytes left to read.
+    #[inline]
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Advance the cursor by `n` bytes without returning them.
+    ///
+    /// If fewer than `n` bytes remain, the cursor is advanced to the end of
+    /// the buffer and `None` is returned.
+    #[inline]
+    pub fn skip(&mut self, n: usize) -> Option<()> {
+        if n > self.remaining() {
+            self.pos = self.buf.len();
+            return None;
+        }
+        self.pos += n;
+        Some(())
+    }
+
+    /// Read and return the next `n` bytes, advancing the cursor.
+    ///
+    /// Returns `None` without advancing the cursor if fewer than `n` bytes
+    /// remain.
+    #[inline]
+    pub fn decode(&mut self, n: usize) -> Option<&'a [u8]> {
+        if n > self.remaining() {
+            return None;
+        }
+        let (bytes, rest) = self.buf[self.pos..].split_at(n);
+        let _ = rest;
+        self.pos += n;
+        Some(bytes)
+    }
+
+    /// Read an `n`-byte big-endian unsigned integer, advancing the cursor.
+    ///
+    /// `n` must be no greater than 8. Returns `None` if fewer than `n` bytes
+    /// remain.
+    #[inline]
+    pub fn decode_uint(&mut self, n: usize) -> Option<u64> {
+        let bytes = self.decode(n)?;
+        let mut value = 0u64;
+        for &byte in bytes {
+            value = (value << 8) | u64::from(byte);
+        }
+        Some(value)
+    }
+
+    /// Read a length-prefixed run of bytes: an `len_bytes`-byte big-endian
+    /// length prefix followed by that many bytes of content.
+    ///
+    /// Returns `None` if either the length prefix or the content run short.
+    #[inline]
+    pub fn decode_vec(&mut self, len_bytes: usize) -> Option<Vec<u8>> {
+        // Read through a copy so a short content run leaves `self` where it
+        // was, length prefix included.
+        let mut cursor = *self;
+        let len = cursor.decode_uint(len_bytes)?;
+        let len = usize::try_from(len).ok()?;
+        let bytes = cursor.decode(len)?;
+        *self = cursor;
+        Some(bytes.to_vec())
+    }
+}
+
+/// An incremental byte writer over a `BinaryString`'s underlying buffer,
+/// used to implement `Array#pack`.
+#[derive(Debug)]
+pub struct Encoder<'a> {
+    buf: &'a mut Buf,
+}
+
+impl<'a> Encoder<'a> {
+    /// Construct a new `Encoder` that appends to the given `BinaryString`.
+    #[inline]
+    #[must_use]
+    pub fn new(dest: &'a mut BinaryString) -> Self {
+        Self { buf: &mut dest.inner }
+    }
+
+    /// Append a single byte.
+    #[inline]
+    pub fn encode_byte(&mut self, byte: u8) {
+        self.buf.extend(core::iter::once(byte));
+    }
+
+    /// Append a run of bytes.
+    #[inline]
+    pub fn encode(&mut self, bytes: &[u8]) {
+        self.buf.extend(bytes.iter().copied());
+    }
+
+    /// Append an `n`-byte big-endian encoding of `value`.
+    ///
+    /// `n` must be no greater than 8; any bytes of `value` above the `n`th
+    /// are truncated, matching how `Array#pack`'s fixed-width directives
+    /// drop out-of-range high bits.
+    #[inline]
+    pub fn encode_uint(&mut self, n: usize, value: u64) {
+        let bytes = value.to_be_bytes();
+        let start = bytes.len().saturating_sub(n);
+        self.encode(&bytes[start..]);
+    }
+
+    /// Append a length-prefixed run of bytes: an `len_bytes`-byte big-endian
+    /// length prefix followed by `bytes` itself.
+    #[inline]
+    pub fn encode_vec(&mut self, len_bytes: usize, bytes: &[u8]) {
+        #![allow(clippy::cast_possible_truncation)]
+        self.encode_uint(len_bytes, bytes.len() as u64);
+        self.encode(bytes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+
+    use super::{BinaryString, Decoder, Encoder};
+
+    #[test]
+    fn decode_at_exact_remaining_length() {
+        let buf = [1, 2, 3, 4];
+        let mut decoder = Decoder::new(&buf);
+        assert_eq!(decoder.remaining(), 4);
+        assert_eq!(decoder.decode(4), Some(&buf[..]));
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn decode_past_end_returns_none_without_advancing() {
+        let buf = [1, 2, 3];
+        let mut decoder = Decoder::new(&buf);
+        assert_eq!(decoder.decode(4), None);
+        // A failed `decode` must not partially advance the cursor.
+        assert_eq!(decoder.remaining(), 3);
+        assert_eq!(decoder.decode(3), Some(&buf[..]));
+    }
+
+    #[test]
+    fn skip_past_end_returns_none_and_advances_to_end() {
+        let buf = [1, 2, 3];
+        let mut decoder = Decoder::new(&buf);
+        assert_eq!(decoder.skip(10), None);
+        assert_eq!(decoder.remaining(), 0);
+        assert_eq!(decoder.decode(1), None);
+    }
+
+    #[test]
+    fn decode_vec_with_length_prefix_exceeding_remaining_data() {
+        // A 1-byte length prefix claiming 10 bytes of content, but only 2
+        // bytes are actually present.
+        let buf = [10u8, 1, 2];
+        let mut decoder = Decoder::new(&buf);
+        assert_eq!(decoder.decode_vec(1), None);
+    }
+
+    #[test]
+    fn decode_vec_round_trips() {
+        let buf = [3u8, b'a', b'b', b'c'];
+        let mut decoder = Decoder::new(&buf);
+        assert_eq!(decoder.decode_vec(1), Some(vec![b'a', b'b', b'c']));
+        assert_eq!(decoder.remaining(), 0);
+    }
+
+    #[test]
+    fn encode_uint_truncates_high_bytes() {
+        let mut dest = BinaryString::from(Vec::new());
+        let mut encoder = Encoder::new(&mut dest);
+        encoder.encode_uint(2, 0x1122_3344);
+        assert_eq!(&dest[..], &[0x33, 0x44]);
+    }
+
+    #[test]
+    fn encode_vec_writes_length_prefix_then_bytes() {
+        let mut dest = BinaryString::from(Vec::new());
+        let mut encoder = Encoder::new(&mut dest);
+        encoder.encode_vec(1, b"abc");
+        assert_eq!(&dest[..], &[3, b'a', b'b', b'c']);
+    }
+}